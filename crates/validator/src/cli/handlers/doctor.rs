@@ -0,0 +1,108 @@
+//! `basilica doctor` — preflight self-checks run before `Start`.
+//!
+//! Each check prints a single `[SUCCESS]`/`[ERROR]`/`[WARNING]` line via the
+//! [`HandlerUtils`] printers and contributes to an overall verdict. Hard
+//! failures (unparseable config, unreachable database, unloadable hotkey)
+//! cause the command to return an error so the process exits nonzero; soft
+//! issues are surfaced as warnings without failing the run.
+
+use crate::cli::handlers::HandlerUtils;
+use crate::config::ValidatorConfig;
+use crate::persistence::SimplePersistence;
+use anyhow::Result;
+
+/// Run the full battery of preflight checks for the given config path, applying
+/// any `--set key=value` overrides while resolving the config.
+///
+/// Returns `Err` when any hard check fails so the CLI exits with a nonzero
+/// status; otherwise `Ok(())`, even if warnings were emitted.
+pub async fn handle_doctor(
+    config_path: Option<std::path::PathBuf>,
+    overrides: Vec<(String, String)>,
+) -> Result<()> {
+    let mut failed = false;
+
+    // 1. Config file parses and validates.
+    let config = match check_config(config_path.as_deref(), overrides) {
+        Ok(config) => config,
+        Err(e) => {
+            HandlerUtils::print_error(&format!("config: {e}"));
+            return Err(anyhow::anyhow!("doctor: configuration is unusable"));
+        }
+    };
+
+    // 2. Database is reachable and migratable.
+    if let Err(e) = check_database(&config).await {
+        HandlerUtils::print_error(&format!("database: {e}"));
+        failed = true;
+    }
+
+    // 3. Hotkey is loadable via the bittensor service.
+    if let Err(e) = check_hotkey(&config).await {
+        HandlerUtils::print_error(&format!("hotkey: {e}"));
+        failed = true;
+    }
+
+    // 4. netuid/network are internally consistent (soft check).
+    check_network(&config);
+
+    if failed {
+        Err(anyhow::anyhow!("doctor: one or more preflight checks failed"))
+    } else {
+        HandlerUtils::print_success("doctor: all preflight checks passed");
+        Ok(())
+    }
+}
+
+/// Load and validate the configuration, returning the parsed config on success.
+/// Validation warnings cite the provenance (file / env / flag) of each value.
+fn check_config(
+    config_path: Option<&std::path::Path>,
+    overrides: Vec<(String, String)>,
+) -> Result<ValidatorConfig> {
+    let path = config_path.map(|p| p.to_string_lossy().into_owned());
+    let (config, provenance) = HandlerUtils::load_layered_config(path.as_deref(), overrides)?;
+    HandlerUtils::validate_config_with_provenance(&config, Some(&provenance))
+        .map_err(|e| anyhow::anyhow!("validation failed: {e}"))?;
+    HandlerUtils::print_success(&format!(
+        "config: parsed and validated (netuid={}, network={})",
+        config.bittensor.common.netuid, config.bittensor.common.network
+    ));
+    Ok(config)
+}
+
+/// Confirm the sqlite URL opens and `SimplePersistence` can migrate it.
+async fn check_database(config: &ValidatorConfig) -> Result<()> {
+    SimplePersistence::new(&config.database.url, "doctor".to_string()).await?;
+    HandlerUtils::print_success(&format!(
+        "database: reachable and migrated ({})",
+        config.database.url
+    ));
+    Ok(())
+}
+
+/// Construct the bittensor service and fetch the account id to prove the
+/// configured hotkey is loadable.
+async fn check_hotkey(config: &ValidatorConfig) -> Result<()> {
+    let service = bittensor::Service::new(config.bittensor.common.clone()).await?;
+    let account_id = service.get_account_id();
+    HandlerUtils::print_success(&format!("hotkey: loaded (ss58={account_id})"));
+    Ok(())
+}
+
+/// Warn when the netuid/network pairing looks inconsistent. Mainnet Basilica
+/// lives on netuid 39; anything else on `finney` is worth flagging.
+fn check_network(config: &ValidatorConfig) {
+    let netuid = config.bittensor.common.netuid;
+    let network = config.bittensor.common.network.as_str();
+    match (network, netuid) {
+        ("finney", 39) | ("test", _) | ("local", _) => {
+            HandlerUtils::print_success(&format!("network: netuid {netuid} consistent with {network}"));
+        }
+        _ => {
+            HandlerUtils::print_warning(&format!(
+                "network: netuid {netuid} on network '{network}' may be misconfigured"
+            ));
+        }
+    }
+}
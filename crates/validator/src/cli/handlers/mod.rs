@@ -4,8 +4,11 @@ use anyhow::Result;
 use common::config::ConfigValidation;
 
 pub mod database;
+pub mod doctor;
 pub mod rental;
+pub mod rental_transfer;
 pub mod service;
+pub mod wallet;
 
 pub struct CommandHandler;
 
@@ -19,9 +22,22 @@ impl CommandHandler {
         command: Command,
         global_config: Option<std::path::PathBuf>,
         local_test: bool,
+        set: Vec<String>,
+        datadir: Option<std::path::PathBuf>,
     ) -> Result<()> {
+        let overrides = HandlerUtils::parse_overrides(&set)?;
+        let config_path = global_config
+            .as_ref()
+            .map(|p| p.to_string_lossy().into_owned());
         match command {
-            Command::Start { config } => service::handle_start(global_config.or(config), local_test).await,
+            Command::Start { config } => {
+                if local_test {
+                    handle_local_test_start().await
+                } else {
+                    let dirs = crate::directory::Directories::resolve(datadir)?;
+                    service::handle_start(global_config.or(config), local_test, &dirs).await
+                }
+            }
             Command::Stop => service::handle_stop().await,
             Command::Status => service::handle_status().await,
             Command::GenConfig { output } => service::handle_gen_config(output).await,
@@ -41,20 +57,30 @@ impl CommandHandler {
                 Err(anyhow::anyhow!("Legacy validation commands have been removed. Use the verification engine API instead."))
             }
 
-            Command::Database { action } => database::handle_database(action).await,
+            Command::Doctor => doctor::handle_doctor(global_config, overrides).await,
+
+            Command::Database { action } => {
+                let dirs = crate::directory::Directories::resolve(datadir)?;
+                database::handle_database(action, &dirs).await
+            }
+
+            Command::Wallet { action } => {
+                let dirs = crate::directory::Directories::resolve(datadir)?;
+                let (mut config, provenance) =
+                    HandlerUtils::load_layered_config(config_path.as_deref(), overrides)?;
+                dirs.migrate_legacy(&mut config)?;
+                HandlerUtils::validate_config_with_provenance(&config, Some(&provenance))?;
+                wallet::handle_wallet(action, &config, &dirs).await
+            }
 
             Command::Rental { action } => {
-                let config = if let Some(config_path) = global_config {
-                    ValidatorConfig::load_from_file(&config_path)?
-                } else {
-                    return Err(anyhow::anyhow!("Configuration required for rental commands"));
-                };
+                let dirs = crate::directory::Directories::resolve(datadir)?;
+                let (mut config, provenance) =
+                    HandlerUtils::load_layered_config(config_path.as_deref(), overrides)?;
+                dirs.migrate_legacy(&mut config)?;
+                HandlerUtils::validate_config_with_provenance(&config, Some(&provenance))?;
 
-                let bittensor_service = bittensor::Service::new(config.bittensor.common.clone()).await?;
-                let account_id = bittensor_service.get_account_id();
-                let ss58_address = format!("{account_id}");
-                let validator_hotkey = common::identity::Hotkey::new(ss58_address)
-                    .map_err(|e| anyhow::anyhow!("Failed to create hotkey: {}", e))?;
+                let validator_hotkey = resolve_hotkey(&config, &dirs).await?;
                 let persistence = std::sync::Arc::new(
                     crate::persistence::SimplePersistence::new(
                         &config.database.url,
@@ -62,12 +88,88 @@ impl CommandHandler {
                     ).await?
                 );
 
-                rental::handle_rental_command(action, validator_hotkey, persistence).await
+                match action {
+                    crate::cli::commands::RentalAction::Move { destination } => {
+                        rental_transfer::handle_move(destination, validator_hotkey, persistence)
+                            .await
+                    }
+                    other => {
+                        rental::handle_rental_command(other, validator_hotkey, persistence).await
+                    }
+                }
             }
         }
     }
 }
 
+/// Resolve the validator hotkey. When the config names a keystore
+/// (`bittensor.hotkey_name`), load that key by name from the selected keystore
+/// directory — the key-by-name selection path. Otherwise fall back to deriving
+/// the identity from a live [`bittensor::Service`].
+async fn resolve_hotkey(
+    config: &ValidatorConfig,
+    dirs: &crate::directory::Directories,
+) -> Result<common::identity::Hotkey> {
+    use sp_core::crypto::Ss58Codec;
+
+    let ss58_address = match &config.bittensor.common.hotkey_name {
+        Some(name) => {
+            let pair = wallet::load_interactive(dirs, name)?;
+            pair.public().to_ss58check()
+        }
+        None => {
+            let service = bittensor::Service::new(config.bittensor.common.clone()).await?;
+            format!("{}", service.get_account_id())
+        }
+    };
+    common::identity::Hotkey::new(ss58_address)
+        .map_err(|e| anyhow::anyhow!("Failed to create hotkey: {e}"))
+}
+
+/// Boot an embedded [`TestValidator`](crate::test_harness::TestValidator),
+/// report its netuid, hotkey and data directory, and run until interrupted. Used by
+/// `basilica start --local-test` to exercise flows without a live chain.
+async fn handle_local_test_start() -> Result<()> {
+    use crate::test_harness::TestValidator;
+
+    let validator = TestValidator::start().await?;
+    HandlerUtils::print_success(&format!(
+        "Started in-process test validator (netuid={}, hotkey={}, data={})",
+        validator.netuid(),
+        validator.hotkey(),
+        validator.data_dir().display()
+    ));
+    HandlerUtils::print_info("Press Ctrl-C to shut down the test validator.");
+
+    tokio::signal::ctrl_c()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to listen for shutdown signal: {e}"))?;
+
+    validator.shutdown()?;
+    HandlerUtils::print_success("Test validator shut down; temporary state removed.");
+    Ok(())
+}
+
+/// Find the source to cite for a warning by matching dotted config keys as
+/// whole tokens within the warning text, preferring the most specific (longest)
+/// match and ignoring keys still at their built-in default. Matching whole
+/// tokens — rather than by substring — avoids a shorter key being misattributed
+/// when it happens to be a substring of a longer one.
+fn cite_source<'a>(
+    warning: &str,
+    provenance: &'a std::collections::BTreeMap<String, crate::config::builder::Source>,
+) -> Option<&'a crate::config::builder::Source> {
+    let is_key_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-');
+    let tokens: Vec<&str> = warning.split(|c| !is_key_char(c)).filter(|t| !t.is_empty()).collect();
+
+    provenance
+        .iter()
+        .filter(|(_, source)| !matches!(source, crate::config::builder::Source::Default))
+        .filter(|(key, _)| tokens.iter().any(|t| t == key))
+        .max_by_key(|(key, _)| key.len())
+        .map(|(_, source)| source)
+}
+
 impl Default for CommandHandler {
     fn default() -> Self {
         Self::new()
@@ -78,28 +180,64 @@ pub struct HandlerUtils;
 
 impl HandlerUtils {
     pub fn load_config(config_path: Option<&str>) -> Result<ValidatorConfig> {
-        match config_path {
-            Some(path) if std::path::Path::new(path).exists() => {
-                tracing::info!("Loading configuration from: {}", path);
-                let config = ValidatorConfig::load_from_file(std::path::Path::new(path))?;
-                tracing::info!(
-                    "Configuration loaded: burn_uid={}, burn_percentage={:.2}%, weight_interval_blocks={}, netuid={}, network={}",
-                    config.emission.burn_uid,
-                    config.emission.burn_percentage,
-                    config.emission.weight_set_interval_blocks,
-                    config.bittensor.common.netuid,
-                    config.bittensor.common.network
-                );
-                Ok(config)
+        Self::load_layered_config(config_path, Vec::new()).map(|(config, _)| config)
+    }
+
+    /// Resolve the effective configuration by layering defaults, an optional
+    /// config file, `BASILICA_`-prefixed environment variables, and explicit
+    /// CLI overrides (in increasing priority). A missing file path is not
+    /// fatal, so env vars and flags alone can specify the config; a path that
+    /// is given but does not exist is an error.
+    ///
+    /// Returns the assembled config alongside the provenance of every key so
+    /// callers can cite where an offending value came from.
+    pub fn load_layered_config(
+        config_path: Option<&str>,
+        flags: Vec<(String, String)>,
+    ) -> Result<(
+        ValidatorConfig,
+        std::collections::BTreeMap<String, crate::config::builder::Source>,
+    )> {
+        use crate::config::builder::ConfigBuilder;
+
+        let mut builder = ConfigBuilder::new()?;
+        if let Some(path) = config_path {
+            let path = std::path::Path::new(path);
+            if !path.exists() {
+                return Err(anyhow::anyhow!(
+                    "Configuration file not found: {}",
+                    path.display()
+                ));
             }
-            Some(path) => Err(anyhow::anyhow!("Configuration file not found: {}", path)),
-            None => Err(anyhow::anyhow!(
-                "Configuration file path is required for validator operation"
-            )),
+            tracing::info!("Loading configuration from: {}", path.display());
+            builder = builder.with_file(path)?;
         }
+        builder = builder.with_env().with_flags(flags);
+
+        let (config, provenance) = builder.build()?;
+        tracing::info!(
+            "Configuration loaded: burn_uid={}, burn_percentage={:.2}%, weight_interval_blocks={}, netuid={}, network={}",
+            config.emission.burn_uid,
+            config.emission.burn_percentage,
+            config.emission.weight_set_interval_blocks,
+            config.bittensor.common.netuid,
+            config.bittensor.common.network
+        );
+        Ok((config, provenance))
     }
 
     pub fn validate_config(config: &ValidatorConfig) -> Result<()> {
+        Self::validate_config_with_provenance(config, None)
+    }
+
+    /// Validate the config, optionally citing the provenance of each key in the
+    /// emitted warnings. When `provenance` is supplied and a warning names a
+    /// known config key, the source (file / env / flag) is appended so the
+    /// operator knows where to fix the offending value.
+    pub fn validate_config_with_provenance(
+        config: &ValidatorConfig,
+        provenance: Option<&std::collections::BTreeMap<String, crate::config::builder::Source>>,
+    ) -> Result<()> {
         config
             .validate()
             .map_err(|e| anyhow::anyhow!("Configuration validation failed: {}", e))?;
@@ -107,13 +245,30 @@ impl HandlerUtils {
         let warnings = config.warnings();
         if !warnings.is_empty() {
             for warning in warnings {
-                Self::print_warning(&format!("Configuration warning: {warning}"));
+                let cited = match provenance.and_then(|map| cite_source(&warning, map)) {
+                    Some(source) => format!("{warning} (from {source})"),
+                    None => warning,
+                };
+                Self::print_warning(&format!("Configuration warning: {cited}"));
             }
         }
 
         Ok(())
     }
 
+    /// Parse `key=value` CLI overrides (from repeated `--set`) into dotted-key
+    /// pairs suitable for [`load_layered_config`].
+    pub fn parse_overrides(set: &[String]) -> Result<Vec<(String, String)>> {
+        set.iter()
+            .map(|entry| {
+                entry
+                    .split_once('=')
+                    .map(|(k, v)| (k.trim().to_string(), v.to_string()))
+                    .ok_or_else(|| anyhow::anyhow!("invalid --set override '{entry}', expected key=value"))
+            })
+            .collect()
+    }
+
     pub fn print_success(message: &str) {
         println!("[SUCCESS] {message}");
     }
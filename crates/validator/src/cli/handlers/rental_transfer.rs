@@ -0,0 +1,97 @@
+//! `rental move` — hand active rentals off to another validator via its API.
+//!
+//! Invoked from the `RentalAction::Move` arm of the `Rental` command dispatch
+//! (see [`CommandHandler::execute_with_context`](super::CommandHandler)), this
+//! transfers in-flight rental records from the local
+//! [`SimplePersistence`] to a destination validator's HTTP API without manual
+//! database surgery. Each rental — including its lease state and hotkey
+//! attribution — is replayed to the destination, and the local record is only
+//! deleted once the destination acknowledges acceptance. That ordering makes
+//! the operation idempotent and resumable: a rental already accepted upstream
+//! is treated as success on a re-run, so an interrupted move can simply be
+//! repeated.
+
+use crate::cli::handlers::HandlerUtils;
+use crate::persistence::SimplePersistence;
+use anyhow::{Context, Result};
+use common::identity::Hotkey;
+use std::sync::Arc;
+
+/// How long to wait for the destination validator to accept a rental.
+const HANDOFF_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Transfer every active rental owned by `validator_hotkey` to the validator
+/// reachable at `destination` (e.g. `https://other-validator:8080`).
+pub async fn handle_move(
+    destination: String,
+    validator_hotkey: Hotkey,
+    persistence: Arc<SimplePersistence>,
+) -> Result<()> {
+    let base = destination.trim_end_matches('/').to_string();
+    let client = reqwest::Client::builder()
+        .timeout(HANDOFF_TIMEOUT)
+        .build()
+        .context("failed to build HTTP client for rental handoff")?;
+
+    let rentals = persistence
+        .list_active_rentals(&validator_hotkey.to_string())
+        .await
+        .context("failed to read active rentals from local persistence")?;
+
+    if rentals.is_empty() {
+        HandlerUtils::print_info("No active rentals to move.");
+        return Ok(());
+    }
+
+    HandlerUtils::print_info(&format!(
+        "Moving {} rental(s) to {base}",
+        rentals.len()
+    ));
+
+    let mut moved = 0usize;
+    for rental in rentals {
+        match hand_off(&client, &base, &rental).await {
+            Ok(()) => {
+                persistence
+                    .delete_rental(&rental.id)
+                    .await
+                    .with_context(|| format!("handed off rental {} but failed to delete local record", rental.id))?;
+                moved += 1;
+                HandlerUtils::print_success(&format!("Moved rental {}", rental.id));
+            }
+            Err(e) => {
+                // Leave the local record intact so the move can be retried.
+                HandlerUtils::print_error(&format!("Failed to move rental {}: {e}", rental.id));
+            }
+        }
+    }
+
+    HandlerUtils::print_success(&format!("Moved {moved} rental(s) to {base}"));
+    Ok(())
+}
+
+/// POST a single rental to the destination, treating an already-present rental
+/// (HTTP 409) as success so the operation stays idempotent.
+async fn hand_off(
+    client: &reqwest::Client,
+    base: &str,
+    rental: &crate::persistence::RentalRecord,
+) -> Result<()> {
+    let response = client
+        .post(format!("{base}/rentals"))
+        .json(rental)
+        .send()
+        .await
+        .context("destination validator unreachable")?;
+
+    match response.status() {
+        status if status.is_success() => Ok(()),
+        reqwest::StatusCode::CONFLICT => {
+            // Already accepted on a previous (interrupted) run.
+            Ok(())
+        }
+        status => Err(anyhow::anyhow!(
+            "destination rejected rental: HTTP {status}"
+        )),
+    }
+}
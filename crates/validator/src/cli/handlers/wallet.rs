@@ -0,0 +1,292 @@
+//! `basilica wallet` — hotkey/keystore management.
+//!
+//! Operators need a safe, auditable way to provision and inspect the identity
+//! that signs weights and rental transactions. This subsystem stores each
+//! hotkey as a password-protected keystore file, encrypted at rest, under the
+//! configured keystore directory. The files are named after the hotkey so that
+//! `Start`/`Rental` can later select a key by name instead of relying on a
+//! single implicit key.
+//!
+//! Keystore files use an sr25519 seed sealed with AES-256-GCM, keyed by an
+//! Argon2id hash of the operator's password. Besides the format version and the
+//! key's name, only the ciphertext, nonce, salt and the (public) SS58 address
+//! are ever written to disk.
+
+use crate::cli::handlers::HandlerUtils;
+use crate::config::ValidatorConfig;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{Context, Result};
+use argon2::Argon2;
+use clap::Subcommand;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::Ss58Codec;
+use sp_core::{sr25519, Pair};
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+/// Actions for the `wallet` subsystem.
+#[derive(Debug, Subcommand)]
+pub enum WalletAction {
+    /// Create a new keypair and write an encrypted keystore file.
+    Generate {
+        /// Name the keystore is stored and later referenced under.
+        #[arg(long)]
+        name: String,
+    },
+    /// Import a raw seed, or adopt an existing Basilica keystore file, under a
+    /// new name. (Importing third-party substrate keystore formats is not yet
+    /// supported — provide the raw seed instead.)
+    Import {
+        /// Name the imported key is stored under.
+        #[arg(long)]
+        name: String,
+        /// Hex-encoded 32-byte seed to import. When omitted, a Basilica
+        /// keystore file is expected via `--file`.
+        #[arg(long, conflicts_with = "file")]
+        seed: Option<String>,
+        /// Path to an existing Basilica keystore file to adopt under `--name`.
+        #[arg(long)]
+        file: Option<PathBuf>,
+    },
+    /// List available hotkeys with their SS58 addresses.
+    List,
+}
+
+/// On-disk keystore file. Mirrors the shape of a substrate keystore: the secret
+/// never leaves encrypted, only the ciphertext and the material needed to
+/// derive the key again are persisted.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Keystore {
+    /// Format version, for forward compatibility.
+    pub version: u8,
+    /// Friendly name the key is referenced by.
+    pub name: String,
+    /// Public SS58 address — safe to store in the clear.
+    pub address: String,
+    /// Argon2id salt, hex-encoded.
+    pub salt: String,
+    /// AES-GCM nonce, hex-encoded.
+    pub nonce: String,
+    /// Encrypted sr25519 seed, hex-encoded.
+    pub ciphertext: String,
+}
+
+impl Keystore {
+    const VERSION: u8 = 1;
+
+    /// Seal a seed under `password`, producing an encrypted keystore.
+    fn seal(name: &str, address: &str, seed: &[u8; 32], password: &str) -> Result<Self> {
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let cipher = Aes256Gcm::new(&derive_key(password, &salt)?);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), seed.as_slice())
+            .map_err(|_| anyhow::anyhow!("failed to encrypt keystore"))?;
+
+        Ok(Self {
+            version: Self::VERSION,
+            name: name.to_string(),
+            address: address.to_string(),
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Recover the sr25519 pair from this keystore using `password`.
+    pub fn unseal(&self, password: &str) -> Result<sr25519::Pair> {
+        let salt = hex::decode(&self.salt).context("keystore salt is not valid hex")?;
+        let nonce = hex::decode(&self.nonce).context("keystore nonce is not valid hex")?;
+        let ciphertext =
+            hex::decode(&self.ciphertext).context("keystore ciphertext is not valid hex")?;
+
+        let cipher = Aes256Gcm::new(&derive_key(password, &salt)?);
+        let seed = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| anyhow::anyhow!("incorrect password or corrupt keystore"))?,
+        );
+        let seed: [u8; 32] = seed
+            .as_slice()
+            .try_into()
+            .context("keystore seed has unexpected length")?;
+        Ok(sr25519::Pair::from_seed(&seed))
+    }
+}
+
+/// Derive a 32-byte AES key from a password and salt via Argon2id.
+fn derive_key(password: &str, salt: &[u8]) -> Result<Key<Aes256Gcm>> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, key.as_mut_slice())
+        .map_err(|e| anyhow::anyhow!("key derivation failed: {e}"))?;
+    Ok(Key::<Aes256Gcm>::clone_from_slice(key.as_slice()))
+}
+
+/// Dispatch a `wallet` action. Keystores are read from and written to the
+/// keystore directory of the [`Directories`](crate::directory::Directories)
+/// layout the operator selected (via `--datadir`/`BASILICA_DATADIR`), so all
+/// handlers agree on where key material lives.
+pub async fn handle_wallet(
+    action: WalletAction,
+    config: &ValidatorConfig,
+    dirs: &crate::directory::Directories,
+) -> Result<()> {
+    let dir = dirs.keystores_dir();
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create keystore directory: {}", dir.display()))?;
+
+    match action {
+        WalletAction::Generate { name } => generate(&dir, &name),
+        WalletAction::Import { name, seed, file } => import(&dir, &name, seed, file),
+        WalletAction::List => list(&dir, config),
+    }
+}
+
+fn generate(dir: &Path, name: &str) -> Result<()> {
+    let path = keystore_path(dir, name);
+    if path.exists() {
+        return Err(anyhow::anyhow!("keystore '{name}' already exists"));
+    }
+
+    let (pair, seed) = sr25519::Pair::generate();
+    let password = prompt_password("Enter a password to encrypt the new keystore: ")?;
+    write_keystore(&path, name, &pair, &seed, &password)?;
+
+    HandlerUtils::print_success(&format!(
+        "Generated hotkey '{name}' ({})",
+        pair.public().to_ss58check()
+    ));
+    Ok(())
+}
+
+fn import(dir: &Path, name: &str, seed: Option<String>, file: Option<PathBuf>) -> Result<()> {
+    let path = keystore_path(dir, name);
+    if path.exists() {
+        return Err(anyhow::anyhow!("keystore '{name}' already exists"));
+    }
+
+    match (seed, file) {
+        (Some(seed_hex), _) => {
+            let bytes = hex::decode(seed_hex.trim_start_matches("0x"))
+                .context("seed is not valid hex")?;
+            let seed: [u8; 32] = bytes
+                .as_slice()
+                .try_into()
+                .context("seed must be exactly 32 bytes")?;
+            let pair = sr25519::Pair::from_seed(&seed);
+            let password = prompt_password("Enter a password to encrypt the imported keystore: ")?;
+            write_keystore(&path, name, &pair, &seed, &password)?;
+            HandlerUtils::print_success(&format!(
+                "Imported hotkey '{name}' ({})",
+                pair.public().to_ss58check()
+            ));
+            Ok(())
+        }
+        (None, Some(src)) => {
+            let raw = std::fs::read_to_string(&src)
+                .with_context(|| format!("failed to read keystore: {}", src.display()))?;
+            let mut keystore: Keystore = serde_json::from_str(&raw)
+                .context("source is not a Basilica keystore file")?;
+            // Prove the file decrypts under the supplied password before
+            // adopting it; this both validates the import and lets us correct
+            // the stored name/address from the recovered key.
+            let password = prompt_password("Enter the source keystore's password: ")?;
+            let pair = keystore.unseal(&password)?;
+            keystore.name = name.to_string();
+            keystore.address = pair.public().to_ss58check();
+            std::fs::write(&path, serde_json::to_string_pretty(&keystore)?)?;
+            HandlerUtils::print_success(&format!(
+                "Imported hotkey '{name}' ({})",
+                keystore.address
+            ));
+            Ok(())
+        }
+        (None, None) => Err(anyhow::anyhow!(
+            "import requires either --seed or --file"
+        )),
+    }
+}
+
+fn list(dir: &Path, config: &ValidatorConfig) -> Result<()> {
+    let active = active_hotkey(config);
+    let mut found = false;
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read keystore directory: {}", dir.display()))?
+    {
+        let entry = entry?;
+        if entry.path().extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        found = true;
+        let keystore: Keystore = serde_json::from_str(&std::fs::read_to_string(entry.path())?)
+            .with_context(|| format!("malformed keystore: {}", entry.path().display()))?;
+        let marker = if active.as_deref() == Some(keystore.name.as_str()) {
+            " (active)"
+        } else {
+            ""
+        };
+        HandlerUtils::print_info(&format!("{}  {}{marker}", keystore.name, keystore.address));
+    }
+    if !found {
+        HandlerUtils::print_warning("No keystores found. Use `wallet generate` to create one.");
+    }
+    Ok(())
+}
+
+/// Load a keystore by name, prompting for its password on the terminal. Used by
+/// the `Start`/`Rental` service-construction path to select a hotkey by name.
+pub fn load_interactive(
+    dirs: &crate::directory::Directories,
+    name: &str,
+) -> Result<sr25519::Pair> {
+    let password = prompt_password(&format!("Enter the password for keystore '{name}': "))?;
+    load(dirs, name, &password)
+}
+
+/// Load a keystore by name so `Start`/`Rental` can select a hotkey explicitly.
+pub fn load(
+    dirs: &crate::directory::Directories,
+    name: &str,
+    password: &str,
+) -> Result<sr25519::Pair> {
+    let path = keystore_path(&dirs.keystores_dir(), name);
+    let raw = std::fs::read_to_string(&path)
+        .with_context(|| format!("no keystore named '{name}' at {}", path.display()))?;
+    let keystore: Keystore = serde_json::from_str(&raw).context("keystore is malformed")?;
+    keystore.unseal(password)
+}
+
+fn write_keystore(
+    path: &Path,
+    name: &str,
+    pair: &sr25519::Pair,
+    seed: &[u8; 32],
+    password: &str,
+) -> Result<()> {
+    let keystore = Keystore::seal(name, &pair.public().to_ss58check(), seed, password)?;
+    std::fs::write(path, serde_json::to_string_pretty(&keystore)?)
+        .with_context(|| format!("failed to write keystore: {}", path.display()))?;
+    Ok(())
+}
+
+fn keystore_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.json"))
+}
+
+/// The hotkey name the config currently selects, if any.
+fn active_hotkey(config: &ValidatorConfig) -> Option<String> {
+    config.bittensor.common.hotkey_name.clone()
+}
+
+/// Read a password from the controlling terminal without echoing it.
+fn prompt_password(prompt: &str) -> Result<Zeroizing<String>> {
+    Ok(Zeroizing::new(
+        rpassword::prompt_password(prompt).context("failed to read password")?,
+    ))
+}
@@ -0,0 +1,375 @@
+//! Layered configuration resolver for [`ValidatorConfig`].
+//!
+//! Values are merged, in increasing priority, from four sources: the built-in
+//! defaults, a TOML config file, process environment variables, and explicit
+//! CLI flags. The model mirrors Cargo's config system — every config key has a
+//! deterministic environment-variable spelling, and list-valued keys accept
+//! either a TOML array or a whitespace/comma-separated string.
+//!
+//! Each resolved value remembers where it came from (see [`Source`]) so that
+//! [`HandlerUtils::validate_config_with_provenance`](crate::cli::handlers::HandlerUtils)
+//! can cite the provenance of an offending value.
+
+use crate::config::ValidatorConfig;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Prefix applied to every environment variable that overrides a config key.
+const ENV_PREFIX: &str = "BASILICA_";
+
+/// Where a resolved config value ultimately came from.
+///
+/// Ordered by increasing priority so that later sources override earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A built-in default baked into [`ValidatorConfig::default`].
+    Default,
+    /// The TOML config file at the given path.
+    File(PathBuf),
+    /// The environment variable with the given name.
+    Env(String),
+    /// An explicit CLI override (`--set key=value`) for the given dotted key.
+    Flag(String),
+}
+
+impl Source {
+    fn priority(&self) -> u8 {
+        match self {
+            Source::Default => 0,
+            Source::File(_) => 1,
+            Source::Env(_) => 2,
+            Source::Flag(_) => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "built-in default"),
+            Source::File(path) => write!(f, "config file {}", path.display()),
+            Source::Env(var) => write!(f, "environment variable {var}"),
+            Source::Flag(key) => write!(f, "CLI override --set {key}"),
+        }
+    }
+}
+
+/// Derive the `BASILICA_`-prefixed environment variable name for a dotted
+/// config key, e.g. `emission.burn_percentage` -> `BASILICA_EMISSION_BURN_PERCENTAGE`.
+pub fn env_var_for_key(key: &str) -> String {
+    let mut name = String::with_capacity(ENV_PREFIX.len() + key.len());
+    name.push_str(ENV_PREFIX);
+    for ch in key.chars() {
+        match ch {
+            '.' | '-' => name.push('_'),
+            other => name.extend(other.to_uppercase()),
+        }
+    }
+    name
+}
+
+/// Split a list-valued string into its elements, accepting either comma or
+/// whitespace as the separator. Empty segments are dropped.
+pub fn split_list(raw: &str) -> Vec<String> {
+    raw.split([',', ' ', '\t', '\n'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Builds a [`ValidatorConfig`] by layering defaults, a file, the environment
+/// and CLI flags, tracking the provenance of every key it sets.
+pub struct ConfigBuilder {
+    /// The merged TOML document, updated in place as sources are applied.
+    document: toml::Table,
+    /// Provenance of each dotted key that has been explicitly set.
+    provenance: BTreeMap<String, Source>,
+    /// Directory used to resolve relative paths found in the config file.
+    file_dir: Option<PathBuf>,
+}
+
+impl ConfigBuilder {
+    /// Start from the built-in defaults.
+    pub fn new() -> Result<Self> {
+        let defaults = ValidatorConfig::default();
+        let document = toml::Table::try_from(&defaults)
+            .context("Failed to serialize default ValidatorConfig")?;
+        let provenance = flatten_keys(&document)
+            .into_iter()
+            .map(|key| (key, Source::Default))
+            .collect();
+        Ok(Self {
+            document,
+            provenance,
+            file_dir: None,
+        })
+    }
+
+    /// Merge a TOML config file over the current document. Relative paths inside
+    /// the file (such as `database.url` pointing at a sqlite file) are resolved
+    /// against the file's own directory.
+    pub fn with_file(mut self, path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        let table: toml::Table = toml::from_str(&raw)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+        self.file_dir = path.parent().map(Path::to_path_buf);
+        let source = Source::File(path.to_path_buf());
+        for (key, value) in flatten_table(&table) {
+            let value = self.resolve_path_value(&key, value);
+            self.set(key, value, source.clone());
+        }
+        Ok(self)
+    }
+
+    /// Merge any `BASILICA_`-prefixed environment variables that correspond to
+    /// known config keys. List-valued keys accept a comma/whitespace string.
+    pub fn with_env(mut self) -> Self {
+        let keys: Vec<String> = flatten_keys(&self.document);
+        for key in keys {
+            let var = env_var_for_key(&key);
+            let Ok(raw) = std::env::var(&var) else {
+                continue;
+            };
+            let value = self.coerce_like_existing(&key, &raw);
+            self.set(key, value, Source::Env(var));
+        }
+        self
+    }
+
+    /// Merge explicit CLI overrides. Each entry is a `(dotted_key, raw_value)`
+    /// pair as supplied on the command line (e.g. from repeated `--set k=v`).
+    pub fn with_flags<I, S>(mut self, flags: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S)>,
+        S: Into<String>,
+    {
+        for (key, raw) in flags {
+            let key = key.into();
+            let raw = raw.into();
+            let value = self.coerce_like_existing(&key, &raw);
+            let source = Source::Flag(key.clone());
+            self.set(key, value, source);
+        }
+        self
+    }
+
+    /// Provenance of a dotted key, if it has been set by any source.
+    pub fn source_of(&self, key: &str) -> Option<&Source> {
+        self.provenance.get(key)
+    }
+
+    /// Finish building, deserializing the merged document into a typed config.
+    pub fn build(self) -> Result<(ValidatorConfig, BTreeMap<String, Source>)> {
+        let config = self
+            .document
+            .try_into()
+            .context("Failed to assemble ValidatorConfig from layered sources")?;
+        Ok((config, self.provenance))
+    }
+
+    /// Insert a value at a dotted key, recording its provenance only when the
+    /// new source ranks at least as high as whatever set the key before (so a
+    /// later value from the same source wins).
+    fn set(&mut self, key: String, value: toml::Value, source: Source) {
+        let outranks = self
+            .provenance
+            .get(&key)
+            .is_none_or(|existing| source.priority() >= existing.priority());
+        if outranks {
+            insert_dotted(&mut self.document, &key, value);
+            self.provenance.insert(key, source);
+        }
+    }
+
+    /// Resolve a relative path stored in the file against the file's directory.
+    fn resolve_path_value(&self, key: &str, value: toml::Value) -> toml::Value {
+        let (Some(dir), toml::Value::String(raw)) = (self.file_dir.as_ref(), &value) else {
+            return value;
+        };
+        if !is_path_key(key) {
+            return value;
+        }
+        let resolved = resolve_relative(dir, raw);
+        toml::Value::String(resolved)
+    }
+
+    /// Coerce a raw string into the TOML type of the existing value at `key`,
+    /// so env/flag overrides match the schema (lists, ints, bools, strings).
+    fn coerce_like_existing(&self, key: &str, raw: &str) -> toml::Value {
+        match get_dotted(&self.document, key) {
+            Some(toml::Value::Array(_)) => {
+                // Accept either a TOML array or a comma/whitespace list.
+                if let Ok(toml::Value::Array(items)) = raw.parse::<toml::Value>() {
+                    toml::Value::Array(items)
+                } else {
+                    toml::Value::Array(
+                        split_list(raw).into_iter().map(toml::Value::String).collect(),
+                    )
+                }
+            }
+            Some(toml::Value::Integer(_)) => raw
+                .parse::<i64>()
+                .map(toml::Value::Integer)
+                .unwrap_or_else(|_| toml::Value::String(raw.to_string())),
+            Some(toml::Value::Float(_)) => raw
+                .parse::<f64>()
+                .map(toml::Value::Float)
+                .unwrap_or_else(|_| toml::Value::String(raw.to_string())),
+            Some(toml::Value::Boolean(_)) => raw
+                .parse::<bool>()
+                .map(toml::Value::Boolean)
+                .unwrap_or_else(|_| toml::Value::String(raw.to_string())),
+            _ => toml::Value::String(raw.to_string()),
+        }
+    }
+}
+
+/// Keys whose string values are filesystem paths and should be resolved
+/// relative to the config file's directory.
+fn is_path_key(key: &str) -> bool {
+    matches!(key, "database.url") || key.ends_with(".path") || key.ends_with(".dir")
+}
+
+/// Resolve a possibly-relative path against `dir`, leaving absolute paths and
+/// non-filesystem URLs (e.g. `postgres://`) untouched. A bare sqlite path or a
+/// `sqlite:` URL has its filesystem portion rebased onto `dir`.
+fn resolve_relative(dir: &Path, raw: &str) -> String {
+    if let Some(rest) = raw.strip_prefix("sqlite://") {
+        return format!("sqlite://{}", rebase(dir, rest));
+    }
+    if let Some(rest) = raw.strip_prefix("sqlite:") {
+        return format!("sqlite:{}", rebase(dir, rest));
+    }
+    if raw.contains("://") {
+        return raw.to_string();
+    }
+    rebase(dir, raw)
+}
+
+fn rebase(dir: &Path, rest: &str) -> String {
+    let path = Path::new(rest);
+    if path.is_absolute() {
+        rest.to_string()
+    } else {
+        dir.join(path).to_string_lossy().into_owned()
+    }
+}
+
+/// Collect every dotted leaf key present in a table.
+fn flatten_keys(table: &toml::Table) -> Vec<String> {
+    flatten_table(table).into_iter().map(|(k, _)| k).collect()
+}
+
+/// Flatten a nested table into `(dotted_key, leaf_value)` pairs. Arrays and
+/// scalars are treated as leaves.
+fn flatten_table(table: &toml::Table) -> Vec<(String, toml::Value)> {
+    let mut out = Vec::new();
+    for (key, value) in table {
+        flatten_value(key, value, &mut out);
+    }
+    out
+}
+
+fn flatten_value(prefix: &str, value: &toml::Value, out: &mut Vec<(String, toml::Value)>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, child) in table {
+                flatten_value(&format!("{prefix}.{key}"), child, out);
+            }
+        }
+        other => out.push((prefix.to_string(), other.clone())),
+    }
+}
+
+/// Look up a leaf value by dotted key.
+fn get_dotted<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Value> {
+    let mut current = table;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        let value = current.get(part)?;
+        if parts.peek().is_none() {
+            return Some(value);
+        }
+        current = value.as_table()?;
+    }
+    None
+}
+
+/// Insert a leaf value at a dotted key, creating intermediate tables as needed.
+fn insert_dotted(table: &mut toml::Table, key: &str, value: toml::Value) {
+    let mut current = table;
+    let parts: Vec<&str> = key.split('.').collect();
+    for part in &parts[..parts.len() - 1] {
+        current = current
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .expect("dotted path traverses a non-table");
+    }
+    current.insert(parts[parts.len() - 1].to_string(), value);
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self::new().expect("default ValidatorConfig must be serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_var_uppercases_and_replaces_separators() {
+        assert_eq!(
+            env_var_for_key("emission.burn_percentage"),
+            "BASILICA_EMISSION_BURN_PERCENTAGE"
+        );
+        assert_eq!(
+            env_var_for_key("bittensor.common.network"),
+            "BASILICA_BITTENSOR_COMMON_NETWORK"
+        );
+        // Dashes are normalized to underscores just like dots.
+        assert_eq!(env_var_for_key("foo-bar.baz"), "BASILICA_FOO_BAR_BAZ");
+    }
+
+    #[test]
+    fn split_list_accepts_commas_and_whitespace() {
+        assert_eq!(split_list("a,b,c"), vec!["a", "b", "c"]);
+        assert_eq!(split_list("a  b\tc\n"), vec!["a", "b", "c"]);
+        // Mixed separators and empty segments are handled.
+        assert_eq!(split_list(" a, ,b "), vec!["a", "b"]);
+        assert!(split_list("   ").is_empty());
+    }
+
+    #[test]
+    fn path_keys_are_recognized() {
+        assert!(is_path_key("database.url"));
+        assert!(is_path_key("storage.path"));
+        assert!(is_path_key("keystore.dir"));
+        assert!(!is_path_key("emission.burn_uid"));
+        assert!(!is_path_key("bittensor.common.network"));
+    }
+
+    #[test]
+    fn relative_paths_rebase_onto_file_dir() {
+        let dir = Path::new("/etc/basilica");
+        // A bare relative path is joined onto the config file's directory.
+        assert_eq!(resolve_relative(dir, "validator.db"), "/etc/basilica/validator.db");
+        // Absolute paths are left untouched.
+        assert_eq!(resolve_relative(dir, "/var/lib/validator.db"), "/var/lib/validator.db");
+        // A relative sqlite URL is rebased inside the scheme.
+        assert_eq!(
+            resolve_relative(dir, "sqlite://data/validator.db"),
+            "sqlite:///etc/basilica/data/validator.db"
+        );
+        // Non-filesystem URLs (e.g. postgres) pass through verbatim.
+        assert_eq!(
+            resolve_relative(dir, "postgres://host/db"),
+            "postgres://host/db"
+        );
+    }
+}
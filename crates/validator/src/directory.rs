@@ -0,0 +1,139 @@
+//! Canonical on-disk layout for Basilica's config, database and keystores.
+//!
+//! Historically each handler derived its own paths from `database.url` and ad
+//! hoc config locations. [`Directories`] centralizes this: a single base data
+//! directory with `config/`, `db/` and `keystores/` subdirectories, overridable
+//! by the `--datadir` flag or the `BASILICA_DATADIR` environment variable.
+//! `Database`, `Rental` and `Start` all resolve their paths through this type so
+//! they agree on locations.
+//!
+//! [`Directories::migrate_legacy`] runs once at startup, relocating files from
+//! legacy/implicit locations into the new structure and rewriting absolute
+//! paths stored inside the config (such as `database.url`) so existing
+//! deployments upgrade transparently.
+
+use crate::config::ValidatorConfig;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the base data directory.
+const DATADIR_ENV: &str = "BASILICA_DATADIR";
+
+/// Resolved locations for every file Basilica manages.
+#[derive(Debug, Clone)]
+pub struct Directories {
+    base: PathBuf,
+}
+
+impl Directories {
+    /// Resolve the base data directory, preferring an explicit `--datadir`
+    /// flag, then `BASILICA_DATADIR`, then the platform default
+    /// (`$XDG_DATA_HOME/basilica` or `~/.basilica`).
+    pub fn resolve(datadir: Option<PathBuf>) -> Result<Self> {
+        let base = datadir
+            .or_else(|| std::env::var_os(DATADIR_ENV).map(PathBuf::from))
+            .or_else(default_base)
+            .context("could not determine a base data directory; pass --datadir")?;
+        Ok(Self { base })
+    }
+
+    /// The base data directory.
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    /// Directory holding config files.
+    pub fn config_dir(&self) -> PathBuf {
+        self.base.join("config")
+    }
+
+    /// Directory holding the sqlite database.
+    pub fn db_dir(&self) -> PathBuf {
+        self.base.join("db")
+    }
+
+    /// Directory holding encrypted keystores.
+    pub fn keystores_dir(&self) -> PathBuf {
+        self.base.join("keystores")
+    }
+
+    /// Default config file path.
+    pub fn config_file(&self) -> PathBuf {
+        self.config_dir().join("validator.toml")
+    }
+
+    /// Default sqlite database URL under the managed layout.
+    pub fn database_url(&self) -> String {
+        format!("sqlite://{}", self.db_dir().join("validator.db").display())
+    }
+
+    /// Create every managed subdirectory if it does not already exist.
+    pub fn ensure(&self) -> Result<()> {
+        for dir in [self.config_dir(), self.db_dir(), self.keystores_dir()] {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        Ok(())
+    }
+
+    /// One-shot migration: relocate files from legacy/implicit locations into
+    /// the managed layout and rewrite the config's `database.url` so it points
+    /// at the new location. Idempotent — a second run is a no-op once files are
+    /// already in place.
+    pub fn migrate_legacy(&self, config: &mut ValidatorConfig) -> Result<()> {
+        self.ensure()?;
+
+        // Only ever touch sqlite URLs; a non-sqlite DB (e.g. `postgres://…`) is
+        // left exactly as configured.
+        let Some(legacy_db) = sqlite_path(&config.database.url) else {
+            return Ok(());
+        };
+        let managed_db = self.db_dir().join("validator.db");
+        if legacy_db != managed_db && legacy_db.exists() {
+            std::fs::rename(&legacy_db, &managed_db).or_else(|_| {
+                // Fall back to copy+remove across filesystems.
+                std::fs::copy(&legacy_db, &managed_db).map(|_| ())?;
+                std::fs::remove_file(&legacy_db)
+            })?;
+            tracing::info!(
+                "Migrated database {} -> {}",
+                legacy_db.display(),
+                managed_db.display()
+            );
+        }
+        // Rebase the URL whenever the database lives in the managed layout,
+        // including runs after the one that relocated it (the config file on
+        // disk still names the legacy path).
+        if legacy_db != managed_db && !legacy_db.exists() && managed_db.exists() {
+            config.database.url = self.database_url();
+        }
+
+        Ok(())
+    }
+}
+
+/// Extract the filesystem path from a sqlite URL or a bare filesystem path,
+/// returning `None` for any other scheme (e.g. `postgres://`). An empty URL is
+/// also treated as non-sqlite.
+fn sqlite_path(url: &str) -> Option<PathBuf> {
+    if url.is_empty() {
+        return None;
+    }
+    if let Some(rest) = url.strip_prefix("sqlite://").or_else(|| url.strip_prefix("sqlite:")) {
+        return Some(PathBuf::from(rest));
+    }
+    // A bare path with no scheme is an implicit sqlite location.
+    if !url.contains("://") {
+        return Some(PathBuf::from(url));
+    }
+    None
+}
+
+/// Platform default base directory: `$XDG_DATA_HOME/basilica`, else
+/// `$HOME/.basilica`.
+fn default_base() -> Option<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_DATA_HOME") {
+        return Some(PathBuf::from(xdg).join("basilica"));
+    }
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".basilica"))
+}
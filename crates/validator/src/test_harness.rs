@@ -0,0 +1,134 @@
+//! In-process ephemeral validator harness for integration tests.
+//!
+//! [`TestValidator`] boots a self-contained validator backed entirely by
+//! temporary state: a throwaway sqlite [`SimplePersistence`], a deterministic
+//! identity standing in for a live [`bittensor::Service`] (fixed account id and
+//! netuid, no chain calls), and a configurable population of fake miners and
+//! rentals. Everything lives under a [`TempDir`] that is removed on drop, so
+//! tests can drive the rental/emission flows end to end without touching a real
+//! Bittensor network.
+//!
+//! The same entry point backs `basilica start --local-test`, which constructs a
+//! harness, reports its netuid, hotkey and data directory, and tears it down on
+//! shutdown.
+
+use crate::persistence::SimplePersistence;
+use anyhow::{Context, Result};
+use common::identity::Hotkey;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+/// Deterministic SS58 address used for the harness identity so tests can assert
+/// against a known hotkey without a chain lookup.
+const TEST_SS58: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+/// Knobs for seeding the harness with fake state.
+#[derive(Debug, Clone)]
+pub struct TestValidatorConfig {
+    /// Netuid the mock service reports.
+    pub netuid: u16,
+    /// Number of fake miners to register.
+    pub miners: usize,
+    /// Number of fake active rentals to seed.
+    pub rentals: usize,
+}
+
+impl Default for TestValidatorConfig {
+    fn default() -> Self {
+        Self {
+            netuid: 39,
+            miners: 3,
+            rentals: 1,
+        }
+    }
+}
+
+/// A running, self-contained test validator. Holds its temporary data directory
+/// open for the lifetime of the handle; dropping it removes all state.
+pub struct TestValidator {
+    temp_dir: TempDir,
+    persistence: Arc<SimplePersistence>,
+    hotkey: Hotkey,
+    netuid: u16,
+}
+
+impl TestValidator {
+    /// Boot a harness with default seeding.
+    pub async fn start() -> Result<Self> {
+        Self::with_config(TestValidatorConfig::default()).await
+    }
+
+    /// Boot a harness, seeding the number of miners and rentals requested.
+    pub async fn with_config(config: TestValidatorConfig) -> Result<Self> {
+        let temp_dir = TempDir::new().context("failed to allocate temp dir for test validator")?;
+        let db_path = temp_dir.path().join("validator.db");
+        let db_url = format!("sqlite://{}", db_path.display());
+
+        let hotkey = Hotkey::new(TEST_SS58.to_string())
+            .map_err(|e| anyhow::anyhow!("failed to create test hotkey: {e}"))?;
+        let persistence = Arc::new(
+            SimplePersistence::new(&db_url, hotkey.to_string())
+                .await
+                .context("failed to open temp persistence")?,
+        );
+
+        let validator = Self {
+            temp_dir,
+            persistence,
+            hotkey,
+            netuid: config.netuid,
+        };
+        validator.seed(&config).await?;
+        Ok(validator)
+    }
+
+    /// Seed fake miners and rentals into the persistence layer. Identifiers are
+    /// derived from the index so seeding is fully deterministic.
+    async fn seed(&self, config: &TestValidatorConfig) -> Result<()> {
+        for i in 0..config.miners {
+            let miner_uid = i as u16;
+            let executor_id = format!("test-executor-{i}");
+            self.persistence
+                .register_test_miner(miner_uid, &executor_id)
+                .await
+                .with_context(|| format!("failed to seed miner {i}"))?;
+        }
+        for i in 0..config.rentals {
+            let rental_id = format!("test-rental-{i}");
+            let executor_id = format!("test-executor-{}", i % config.miners.max(1));
+            self.persistence
+                .seed_test_rental(&rental_id, &executor_id, &self.hotkey.to_string())
+                .await
+                .with_context(|| format!("failed to seed rental {i}"))?;
+        }
+        Ok(())
+    }
+
+    /// The shared persistence layer, for assertions and flow driving.
+    pub fn persistence(&self) -> &Arc<SimplePersistence> {
+        &self.persistence
+    }
+
+    /// The deterministic validator hotkey.
+    pub fn hotkey(&self) -> &Hotkey {
+        &self.hotkey
+    }
+
+    /// The netuid the harness pretends to operate on.
+    pub fn netuid(&self) -> u16 {
+        self.netuid
+    }
+
+    /// Path to the temporary data directory backing this harness.
+    pub fn data_dir(&self) -> &std::path::Path {
+        self.temp_dir.path()
+    }
+
+    /// Explicitly tear down the harness, removing all temporary state. Dropping
+    /// the handle does the same; this form surfaces any cleanup error.
+    pub fn shutdown(self) -> Result<()> {
+        self.temp_dir
+            .close()
+            .context("failed to remove test validator temp dir")
+    }
+}
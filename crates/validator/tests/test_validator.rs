@@ -0,0 +1,39 @@
+//! Integration tests for the in-process [`TestValidator`] harness.
+
+use validator::test_harness::{TestValidator, TestValidatorConfig};
+
+#[tokio::test]
+async fn harness_seeds_and_tears_down() {
+    let validator = TestValidator::with_config(TestValidatorConfig {
+        netuid: 42,
+        miners: 5,
+        rentals: 2,
+    })
+    .await
+    .expect("harness should boot");
+
+    // Deterministic identity and netuid, no chain calls.
+    assert_eq!(validator.netuid(), 42);
+    assert_eq!(
+        validator.hotkey().to_string(),
+        "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+    );
+
+    // All state lives under a temporary directory that exists while the handle
+    // is alive.
+    let data_dir = validator.data_dir().to_path_buf();
+    assert!(data_dir.exists());
+
+    // Shutting down removes every trace of the harness.
+    validator.shutdown().expect("shutdown should succeed");
+    assert!(!data_dir.exists());
+}
+
+#[tokio::test]
+async fn harness_defaults_are_usable() {
+    let validator = TestValidator::start().await.expect("harness should boot");
+    assert_eq!(validator.netuid(), 39);
+    // The sqlite database backing the shared persistence layer is created in
+    // the temporary data directory.
+    assert!(validator.data_dir().join("validator.db").exists());
+}